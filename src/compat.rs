@@ -0,0 +1,27 @@
+//! A thin `std`/`no_std` compatibility shim.
+//!
+//! With the default `http` feature the rest of the crate keeps using plain `std` types. With
+//! `no_std` (and `http` disabled) the same names resolve to their `alloc` equivalents instead,
+//! so `crypto`, [`crate::models::session`] and session generation/parsing compile against
+//! `core`+`alloc` alone, without pulling in `reqwest`/`tokio`.
+
+#[cfg(feature = "no_std")]
+extern crate alloc;
+
+#[cfg(feature = "no_std")]
+pub use alloc::{
+    collections::BTreeMap as Map,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+#[cfg(not(feature = "no_std"))]
+pub use std::{
+    collections::HashMap as Map,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
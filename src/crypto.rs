@@ -1,6 +1,7 @@
 /// This module provides cryptographic utilities for the application.
 ///
-/// It includes functions for SHA-1 hashing and AES encryption.
+/// It includes functions for SHA-1 hashing, AES-CBC encryption (used by the `ks` v2 format),
+/// and AES-GCM authenticated encryption for callers that want tamper detection.
 ///
 /// # Example
 ///
@@ -11,21 +12,32 @@
 /// let key = vec![0; AES_KEY_LEN];
 ///
 /// let hashed_data = sha1(&data);
-/// let encrypted_data = aes_encrypt(&mut data.clone(), &key, &AES_IV);
+/// let encrypted_data = aes_encrypt(&mut data.clone(), &key, &AES_IV).unwrap();
 /// ```
 ///
 /// # Note
 ///
-/// This module uses the `aes` and `sha1` crates for encryption and hashing respectively.
+/// This module uses the `aes`, `aes-gcm`, and `sha1` crates for encryption and hashing
+/// respectively. The `aes` crate already dispatches to an AES-NI backend at runtime on
+/// `x86`/`x86_64` when the CPU supports it, falling back to its constant-time software
+/// implementation otherwise; [`hardware_aes_available`] exposes that detection so callers (and
+/// tests) can observe which path is active.
 
 
 use aes::{
     self,
     cipher::{BlockDecryptMut, BlockEncryptMut, KeyIvInit},
 };
+use aes_gcm::{
+    aead::{Aead, KeyInit},
+    Aes128Gcm, Nonce,
+};
 
 use sha1::{Digest, Sha1};
 
+use crate::compat::{format, vec, Vec};
+use crate::error::KalturaError;
+
 
 type Aes128CbcEnc = cbc::Encryptor<aes::Aes128>;
 type Aes128CbcDec = cbc::Decryptor<aes::Aes128>;
@@ -34,8 +46,10 @@ type Aes128CbcDec = cbc::Decryptor<aes::Aes128>;
 pub const AES_KEY_LEN: usize = 16;
 /// The size of the AES block in bytes.
 pub const AES_BLOCK_SIZE: usize = 16;
-/// The initialization vector (IV) for the AES encryption.
+/// The initialization vector (IV) for the AES-CBC encryption.
 pub const AES_IV: [u8; AES_KEY_LEN] = [0x22; AES_KEY_LEN];
+/// The length of the nonce expected by [`aes_gcm_encrypt`] and [`aes_gcm_decrypt`].
+pub const AES_GCM_NONCE_LEN: usize = 12;
 
 /// Computes the SHA-1 hash of the given data.
 ///
@@ -49,17 +63,45 @@ pub fn sha1(data: &Vec<u8>) -> Vec<u8> {
     sig.to_vec()
 }
 
-/// Encrypts the given data using AES encryption.
+/// Reports whether the running CPU advertises the features the hardware AES backend needs
+/// (`AES-NI`, plus the `SSE2`/`SSSE3` prerequisites its key schedule uses).
+///
+/// Detection happens at runtime via [`std::is_x86_feature_detected`], so a binary built without
+/// `target-feature=+aes` still gets the accelerated path on CPUs that support it, and falls back
+/// to the portable software implementation everywhere else. The `aes` crate this module builds
+/// on performs this same dispatch internally; this function exists so callers can observe which
+/// path bulk operations (session minting, `ks` decoding) are actually taking.
+///
+/// `no_std` targets have no portable way to query CPU features at runtime, so this always
+/// reports `false` there and relies on the software fallback.
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "no_std")))]
+pub fn hardware_aes_available() -> bool {
+    std::is_x86_feature_detected!("aes")
+        && std::is_x86_feature_detected!("sse2")
+        && std::is_x86_feature_detected!("ssse3")
+}
+
+/// See the `x86`/`x86_64` overload. Other targets (and `no_std` builds) have no runtime feature
+/// detection available, so this always reports `false` and the software implementation is used.
+#[cfg(not(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "no_std"))))]
+pub fn hardware_aes_available() -> bool {
+    false
+}
+
+/// Encrypts the given data using AES-128-CBC with zero padding.
 ///
 /// # Arguments
 ///
-/// * `data` - The data to encrypt.
+/// * `data` - The data to encrypt. Extended in place to the next block boundary if needed.
 /// * `key` - The encryption key.
 /// * `iv` - The initialization vector.
 ///
-/// # Returns
-/// The encrypted data.
-/// 
+/// # Errors
+///
+/// Returns `Err(KalturaError::Crypto(_))` if the key is shorter than [`AES_KEY_LEN`] bytes or
+/// the IV is not [`AES_BLOCK_SIZE`] bytes. Only the first [`AES_KEY_LEN`] bytes of a longer key
+/// are used.
+///
 /// # Example
 /// ```
 /// use kaltura_client_rs::crypto::{sha1, aes_encrypt, AES_KEY_LEN, AES_IV};
@@ -68,9 +110,15 @@ pub fn sha1(data: &Vec<u8>) -> Vec<u8> {
 /// let key = vec![0; AES_KEY_LEN];
 ///
 /// let hashed_data = sha1(&data);
-/// let encrypted_data = aes_encrypt(&mut data.clone(), &key, &AES_IV);
+/// let encrypted_data = aes_encrypt(&mut data.clone(), &key, &AES_IV).unwrap();
 /// ```
-pub fn aes_encrypt(data: &mut Vec<u8>, key: &Vec<u8>, iv: &[u8]) -> Vec<u8> {
+pub fn aes_encrypt(data: &mut Vec<u8>, key: &Vec<u8>, iv: &[u8]) -> Result<Vec<u8>, KalturaError> {
+    if key.len() < AES_KEY_LEN {
+        return Err(KalturaError::Crypto("aes-cbc key too short".into()));
+    }
+    if iv.len() != AES_BLOCK_SIZE {
+        return Err(KalturaError::Crypto("aes-cbc iv must be 16 bytes".into()));
+    }
     let length = data.len();
     let key = &key[..AES_KEY_LEN];
 
@@ -79,28 +127,141 @@ pub fn aes_encrypt(data: &mut Vec<u8>, key: &Vec<u8>, iv: &[u8]) -> Vec<u8> {
         data.extend(vec![0 as u8; padding]);
     }
 
-    match Aes128CbcEnc::new(key.into(), iv.into())
+    Aes128CbcEnc::new(key.into(), iv.into())
         .encrypt_padded_mut::<aes::cipher::block_padding::ZeroPadding>(data, length)
-    {
-        Ok(cipher) => cipher.to_vec(),
-        Err(e) => {
-            println!("Error: {:?}", e);
-            vec![]
-        }
-    }
+        .map(|cipher| cipher.to_vec())
+        .map_err(|e| KalturaError::Crypto(format!("aes-cbc encrypt failed: {}", e)))
 }
 
-/// Decrypts the given data using AES encryption.
+/// Decrypts data produced by [`aes_encrypt`] (AES-128-CBC with zero padding).
 ///
 /// # Arguments
 ///
-/// * `data` - The data to encrypt.
+/// * `data` - The data to decrypt.
 /// * `key` - The encryption key.
 /// * `iv` - The initialization vector.
-pub fn aes_decrypt(data: &[u8], key: &str, iv: &[u8]) -> Vec<u8> {
+///
+/// # Errors
+///
+/// Returns `Err(KalturaError::Crypto(_))` if the key is shorter than [`AES_KEY_LEN`] bytes, the
+/// IV is not [`AES_BLOCK_SIZE`] bytes, or the padded data is not a whole number of blocks.
+pub fn aes_decrypt(data: &[u8], key: &[u8], iv: &[u8]) -> Result<Vec<u8>, KalturaError> {
+    if key.len() < AES_KEY_LEN {
+        return Err(KalturaError::Crypto("aes-cbc key too short".into()));
+    }
+    if iv.len() != AES_BLOCK_SIZE {
+        return Err(KalturaError::Crypto("aes-cbc iv must be 16 bytes".into()));
+    }
     let mut block = data.to_vec();
-    let cipher = Aes128CbcDec::new(key.as_bytes().into(), iv.into())
+    let key = &key[..AES_KEY_LEN];
+    Aes128CbcDec::new(key.into(), iv.into())
         .decrypt_padded_mut::<aes::cipher::block_padding::ZeroPadding>(&mut block)
-        .unwrap();
-    cipher.to_vec()
-}
\ No newline at end of file
+        .map(|cipher| cipher.to_vec())
+        .map_err(|e| KalturaError::Crypto(format!("aes-cbc decrypt failed: {}", e)))
+}
+
+/// Encrypts `data` with AES-128-GCM, producing ciphertext with an appended authentication tag.
+///
+/// Unlike [`aes_encrypt`], a tampered ciphertext is detected rather than silently decrypting to
+/// garbage, which makes this the right choice for any secret stored or transmitted alongside a
+/// session rather than as part of the `ks` wire format itself.
+///
+/// # Arguments
+///
+/// * `data` - The plaintext to encrypt.
+/// * `key` - The 128-bit encryption key.
+/// * `nonce` - A [`AES_GCM_NONCE_LEN`]-byte value that must never be reused with the same key.
+///
+/// # Errors
+///
+/// Returns `Err(KalturaError::Crypto(_))` if the key is shorter than [`AES_KEY_LEN`] bytes or the
+/// nonce is not [`AES_GCM_NONCE_LEN`] bytes. Only the first [`AES_KEY_LEN`] bytes of a longer
+/// key are used.
+pub fn aes_gcm_encrypt(data: &[u8], key: &[u8], nonce: &[u8]) -> Result<Vec<u8>, KalturaError> {
+    if key.len() < AES_KEY_LEN {
+        return Err(KalturaError::Crypto("aes-gcm key too short".into()));
+    }
+    if nonce.len() != AES_GCM_NONCE_LEN {
+        return Err(KalturaError::Crypto("aes-gcm nonce must be 12 bytes".into()));
+    }
+    let cipher = Aes128Gcm::new(key[..AES_KEY_LEN].into());
+    cipher
+        .encrypt(Nonce::from_slice(nonce), data)
+        .map_err(|e| KalturaError::Crypto(format!("aes-gcm encrypt failed: {}", e)))
+}
+
+/// Decrypts and authenticates data produced by [`aes_gcm_encrypt`].
+///
+/// # Errors
+///
+/// Returns `Err(KalturaError::Crypto(_))` if the key is shorter than [`AES_KEY_LEN`] bytes, the
+/// nonce is not [`AES_GCM_NONCE_LEN`] bytes, or the authentication tag does not match (i.e. `data` was truncated or tampered with).
+pub fn aes_gcm_decrypt(data: &[u8], key: &[u8], nonce: &[u8]) -> Result<Vec<u8>, KalturaError> {
+    if key.len() < AES_KEY_LEN {
+        return Err(KalturaError::Crypto("aes-gcm key too short".into()));
+    }
+    if nonce.len() != AES_GCM_NONCE_LEN {
+        return Err(KalturaError::Crypto("aes-gcm nonce must be 12 bytes".into()));
+    }
+    let cipher = Aes128Gcm::new(key[..AES_KEY_LEN].into());
+    cipher
+        .decrypt(Nonce::from_slice(nonce), data)
+        .map_err(|e| KalturaError::Crypto(format!("aes-gcm decrypt failed: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cbc_round_trips() {
+        let key = vec![0x42; AES_KEY_LEN];
+        let mut plaintext = b"hello kaltura".to_vec();
+        let ciphertext = aes_encrypt(&mut plaintext, &key, &AES_IV).unwrap();
+
+        let decrypted = aes_decrypt(&ciphertext, &key, &AES_IV).unwrap();
+        assert!(decrypted.starts_with(b"hello kaltura"));
+    }
+
+    #[test]
+    fn cbc_decrypt_returns_err_on_malformed_input_instead_of_panicking() {
+        let key = vec![0x42; AES_KEY_LEN];
+        // Not a whole number of AES blocks, so padded decryption can't succeed.
+        let garbage = vec![1, 2, 3, 4, 5];
+
+        assert!(aes_decrypt(&garbage, &key, &AES_IV).is_err());
+    }
+
+    #[test]
+    fn gcm_round_trips() {
+        let key = vec![0x11; AES_KEY_LEN];
+        let nonce = vec![0x22; AES_GCM_NONCE_LEN];
+        let plaintext = b"a secret payload";
+
+        let ciphertext = aes_gcm_encrypt(plaintext, &key, &nonce).unwrap();
+        let decrypted = aes_gcm_decrypt(&ciphertext, &key, &nonce).unwrap();
+
+        assert_eq!(decrypted, plaintext);
+    }
+
+    #[test]
+    fn gcm_detects_tampered_ciphertext() {
+        let key = vec![0x11; AES_KEY_LEN];
+        let nonce = vec![0x22; AES_GCM_NONCE_LEN];
+        let plaintext = b"a secret payload";
+
+        let mut ciphertext = aes_gcm_encrypt(plaintext, &key, &nonce).unwrap();
+        let last = ciphertext.len() - 1;
+        ciphertext[last] ^= 0xff;
+
+        assert!(aes_gcm_decrypt(&ciphertext, &key, &nonce).is_err());
+    }
+
+    #[test]
+    fn gcm_rejects_wrong_nonce_length() {
+        let key = vec![0x11; AES_KEY_LEN];
+        let short_nonce = vec![0x22; AES_GCM_NONCE_LEN - 1];
+
+        assert!(aes_gcm_encrypt(b"data", &key, &short_nonce).is_err());
+    }
+}
@@ -0,0 +1,168 @@
+use core::fmt;
+
+#[cfg(feature = "http")]
+use crate::compat::Map;
+use crate::compat::String;
+#[cfg(feature = "http")]
+use crate::compat::ToString;
+
+/// Errors that can occur while talking to the Kaltura API.
+#[derive(Debug)]
+pub enum KalturaError {
+    /// The HTTP request itself failed (connection, timeout, TLS, etc).
+    #[cfg(feature = "http")]
+    Http(reqwest::Error),
+    /// The response body could not be parsed as JSON.
+    #[cfg(feature = "http")]
+    Deserialization(serde_json::Error),
+    /// Kaltura returned a `KalturaAPIException` response.
+    #[cfg(feature = "http")]
+    ApiException {
+        code: String,
+        message: String,
+        args: Map<String, String>,
+    },
+    /// A `ks` was malformed, could not be decrypted, or failed integrity verification.
+    InvalidSession(String),
+    /// An AES (CBC or GCM) operation failed, e.g. padding was invalid or a GCM tag did not
+    /// authenticate.
+    Crypto(String),
+}
+
+impl fmt::Display for KalturaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "http")]
+            KalturaError::Http(err) => write!(f, "HTTP error: {}", err),
+            #[cfg(feature = "http")]
+            KalturaError::Deserialization(err) => write!(f, "deserialization error: {}", err),
+            #[cfg(feature = "http")]
+            KalturaError::ApiException { code, message, .. } => {
+                write!(f, "Kaltura API exception [{}]: {}", code, message)
+            }
+            KalturaError::InvalidSession(reason) => write!(f, "invalid ks: {}", reason),
+            KalturaError::Crypto(reason) => write!(f, "crypto error: {}", reason),
+        }
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl std::error::Error for KalturaError {}
+
+#[cfg(feature = "http")]
+impl From<reqwest::Error> for KalturaError {
+    fn from(err: reqwest::Error) -> Self {
+        KalturaError::Http(err)
+    }
+}
+
+#[cfg(feature = "http")]
+impl From<serde_json::Error> for KalturaError {
+    fn from(err: serde_json::Error) -> Self {
+        KalturaError::Deserialization(err)
+    }
+}
+
+/// Inspects a raw Kaltura response body for the `KalturaAPIException` shape, returning it as an
+/// `Err(KalturaError::ApiException)` when present and passing the body through unchanged otherwise.
+///
+/// The exception object may appear at the top level or nested under a `result` field, matching
+/// the shapes Kaltura's API returns depending on the endpoint.
+#[cfg(feature = "http")]
+pub(crate) fn parse_api_response(body: String) -> Result<String, KalturaError> {
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(&body) else {
+        return Ok(body);
+    };
+
+    let is_exception = |v: &serde_json::Value| {
+        v.get("objectType").and_then(|t| t.as_str()) == Some("KalturaAPIException")
+    };
+
+    let exception = if is_exception(&value) {
+        Some(&value)
+    } else {
+        value.get("result").filter(|result| is_exception(result))
+    };
+
+    let Some(exception) = exception else {
+        return Ok(body);
+    };
+
+    let code = exception
+        .get("code")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+    let message = exception
+        .get("message")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+    let args = exception
+        .get("args")
+        .and_then(|v| v.as_object())
+        .map(|obj| {
+            obj.iter()
+                .map(|(k, v)| (k.clone(), v.as_str().unwrap_or_default().to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Err(KalturaError::ApiException {
+        code,
+        message,
+        args,
+    })
+}
+
+#[cfg(feature = "http")]
+#[cfg(test)]
+mod tests {
+    use super::parse_api_response;
+    use crate::error::KalturaError;
+
+    #[test]
+    fn passes_through_non_exception_bodies_unchanged() {
+        let body = r#"{"id": 1, "name": "test"}"#.to_string();
+        assert_eq!(parse_api_response(body.clone()).unwrap(), body);
+    }
+
+    #[test]
+    fn parses_top_level_api_exception() {
+        let body = r#"{
+            "objectType": "KalturaAPIException",
+            "code": "INVALID_KS",
+            "message": "The session key is invalid",
+            "args": {"KS": "abc"}
+        }"#
+        .to_string();
+
+        match parse_api_response(body) {
+            Err(KalturaError::ApiException { code, message, args }) => {
+                assert_eq!(code, "INVALID_KS");
+                assert_eq!(message, "The session key is invalid");
+                assert_eq!(args.get("KS"), Some(&"abc".to_string()));
+            }
+            other => panic!("expected ApiException, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parses_api_exception_nested_under_result() {
+        let body = r#"{
+            "result": {
+                "objectType": "KalturaAPIException",
+                "code": "ENTRY_ID_NOT_FOUND",
+                "message": "entry not found"
+            }
+        }"#
+        .to_string();
+
+        match parse_api_response(body) {
+            Err(KalturaError::ApiException { code, .. }) => {
+                assert_eq!(code, "ENTRY_ID_NOT_FOUND");
+            }
+            other => panic!("expected ApiException, got {:?}", other),
+        }
+    }
+}
@@ -1,20 +1,43 @@
+//! With the default `http` feature this crate pulls in `reqwest`/`tokio` for the full
+//! [`KalturaClient`]. Disabling default features and enabling `no_std` instead compiles only
+//! [`crypto`], [`models::session`] and session generation/parsing against `core`+`alloc`, for
+//! devices that only need to mint or inspect a `ks` (e.g. an encoder or kiosk).
+#![cfg_attr(feature = "no_std", no_std)]
+
+#[cfg(feature = "no_std")]
+extern crate alloc;
+
 pub mod crypto;
+pub mod error;
 pub mod models;
-mod service;
+#[cfg(feature = "http")]
+pub mod service;
+mod compat;
 
 use base64::{engine::general_purpose::URL_SAFE, Engine};
+use compat::{format, Map, String, ToString, Vec};
+use error::KalturaError;
+#[cfg(not(feature = "no_std"))]
 use rand;
-use std::{collections::HashMap, time};
+#[cfg(feature = "http")]
+use std::collections::HashMap;
+#[cfg(not(feature = "no_std"))]
+use std::time;
 
+#[cfg(feature = "http")]
 const VERSION: &str = env!("CARGO_PKG_VERSION");
+#[cfg(feature = "http")]
 const KALTURA_API_ENDPOINT: &str = "https://www.kaltura.com/api_v3";
+#[cfg(feature = "http")]
 const USER_AGENT: &str = "kaltura-client-rs";
 
+#[cfg(feature = "http")]
 #[derive(Debug, PartialEq)]
 pub struct KalturaClientConfig {
     pub service_url: Option<String>,
 }
 
+#[cfg(feature = "http")]
 impl Default for KalturaClientConfig {
     fn default() -> Self {
         KalturaClientConfig {
@@ -33,6 +56,7 @@ impl Default for KalturaClientConfig {
 /// use kaltura_client_rs::KalturaClient;
 /// let client = KalturaClient::new();
 /// ```
+#[cfg(feature = "http")]
 #[derive(Default, Debug)]
 pub struct KalturaClient {
     config: KalturaClientConfig,
@@ -41,6 +65,7 @@ pub struct KalturaClient {
     session: models::session::KalturaSession,
 }
 
+#[cfg(feature = "http")]
 impl KalturaClient {
     /// Creates a new `KalturaClient`.
     ///
@@ -90,13 +115,27 @@ impl KalturaClient {
     /// tokio_test::block_on(async {
     ///     let response =  kaltura_client.system()
     ///         .get_version().await;
-    ///    assert!(response.len() > 0);
+    ///    assert!(response.is_ok());
     /// });
     ///     
     /// ```
     pub fn system(&self) -> service::system::SystemService {
-        service::system::SystemService::new(self)
+        service::Service::new(self)
     }
+
+    /// Returns a `UiConfService` for interacting with the uiConf service of the Kaltura API.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kaltura_client_rs::KalturaClient;
+    /// let kaltura_client = KalturaClient::new();
+    /// let uiconf_service = kaltura_client.uiconf();
+    /// ```
+    pub fn uiconf(&self) -> service::uiconf::UiConfService {
+        service::Service::new(self)
+    }
+
     /// Sends a GET request to the specified URL and returns the response as a `String`.
     ///
     /// # Arguments
@@ -152,27 +191,189 @@ impl KalturaClient {
     ///   assert!(response.is_ok());
     /// });
     /// ```
-    pub async fn api_get(&self, service: &str, action: &str) -> Result<String, reqwest::Error> {
-        let request: String = format!(
-            "/service/{}/action/{}",
-            service, action
+    pub async fn api_get(&self, service: &str, action: &str) -> Result<String, KalturaError> {
+        self.api_request(service, action, HashMap::new()).await
+    }
+
+    /// Sends a Kaltura API request with the given parameters, returning the response body as a `String`.
+    ///
+    /// The session `ks` (if one has been established) is merged in automatically. When the
+    /// caller passes no `parameters` of their own, the request is sent as a GET with `ks` (if
+    /// any) encoded as a query string; as soon as the caller supplies any parameters, the request
+    /// is sent as a POST with all of them (including `ks`) as the JSON body.
+    ///
+    /// # Arguments
+    ///
+    /// * `service` - The Kaltura service name, e.g. `"system"`.
+    /// * `action` - The action to invoke on the service, e.g. `"ping"`.
+    /// * `parameters` - The request parameters, keyed by Kaltura parameter name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kaltura_client_rs::KalturaClient;
+    /// use std::collections::HashMap;
+    ///
+    /// let client = KalturaClient::new();
+    ///
+    /// tokio_test::block_on(async {
+    ///     let response = client.api_request("system", "ping", HashMap::new()).await;
+    ///     assert!(response.is_ok());
+    /// });
+    /// ```
+    pub async fn api_request(
+        &self,
+        service: &str,
+        action: &str,
+        mut parameters: HashMap<String, String>,
+    ) -> Result<String, KalturaError> {
+        let has_body_params = !parameters.is_empty();
+
+        if !self.session.ks.is_empty() {
+            parameters.insert("ks".to_string(), self.session.ks.clone());
+        }
+
+        let url: String = format!(
+            "{}/service/{}/action/{}",
+            self.config.service_url.as_ref().unwrap_or(&KALTURA_API_ENDPOINT.to_string()),
+            service,
+            action
         );
-        self.get(&request).await
-    }
-    // pub async fn api_get<T: Serialize + Deserialize>(&self, service: &str, action: &str, parameters: T) -> Result<String, reqwest::Error> {
-    //     let request: String = format!(
-    //         "/service/{}/action/{}",
-    //         service, action
-    //     );
-    //     self.get(&request).await
-    // }
+        let headers = (&self.headers)
+            .try_into()
+            .expect("Error converting headers");
+
+        let resp = if has_body_params {
+            self.http_client
+                .post(&url)
+                .headers(headers)
+                .json(&parameters)
+                .send()
+                .await?
+        } else {
+            self.http_client
+                .get(&url)
+                .headers(headers)
+                .query(&parameters)
+                .send()
+                .await?
+        };
+        error::parse_api_response(resp.text().await?)
+    }
+
+    /// Returns a `MultiRequestBuilder` for queuing several service/action calls and dispatching
+    /// them to Kaltura's `multirequest` service in a single round trip.
+    ///
+    /// An earlier call's result can be referenced from a later call's parameter using Kaltura's
+    /// token syntax, e.g. `"{1:result:id}"` to reference the `id` field of the first queued call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kaltura_client_rs::KalturaClient;
+    /// use std::collections::HashMap;
+    ///
+    /// let client = KalturaClient::new();
+    ///
+    /// tokio_test::block_on(async {
+    ///     let response = client
+    ///         .multirequest()
+    ///         .add("system", "ping", HashMap::new())
+    ///         .add("system", "getTime", HashMap::new())
+    ///         .execute()
+    ///         .await;
+    ///     assert!(response.is_ok());
+    /// });
+    /// ```
+    pub fn multirequest(&self) -> MultiRequestBuilder {
+        MultiRequestBuilder::new(self)
+    }
 }
 
+/// Queues Kaltura service/action calls for dispatch as a single `multirequest`.
+///
+/// Built via [`KalturaClient::multirequest`]. Calls are numbered starting at `1` in the order
+/// they are added, matching Kaltura's convention for the `{N:result:field}` token syntax used
+/// to reference an earlier call's output from a later call's parameter.
+#[cfg(feature = "http")]
+pub struct MultiRequestBuilder<'client> {
+    client: &'client KalturaClient,
+    calls: Vec<(String, String, HashMap<String, String>)>,
+}
+
+#[cfg(feature = "http")]
+impl<'client> MultiRequestBuilder<'client> {
+    fn new(client: &'client KalturaClient) -> MultiRequestBuilder<'client> {
+        MultiRequestBuilder {
+            client,
+            calls: Vec::new(),
+        }
+    }
+
+    /// Queues a service/action call with the given parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `service` - The Kaltura service name, e.g. `"system"`.
+    /// * `action` - The action to invoke on the service, e.g. `"ping"`.
+    /// * `parameters` - The request parameters for this call. May reference an earlier queued
+    ///   call's result via Kaltura's `"{N:result:field}"` token syntax.
+    pub fn add(
+        mut self,
+        service: &str,
+        action: &str,
+        parameters: HashMap<String, String>,
+    ) -> MultiRequestBuilder<'client> {
+        self.calls
+            .push((service.to_string(), action.to_string(), parameters));
+        self
+    }
+
+    /// Dispatches all queued calls in a single round trip and returns their results as an
+    /// ordered `Vec`, one entry per queued call.
+    pub async fn execute(self) -> Result<Vec<String>, KalturaError> {
+        let mut parameters: HashMap<String, String> = HashMap::new();
+        if !self.client.session.ks.is_empty() {
+            parameters.insert("ks".to_string(), self.client.session.ks.clone());
+        }
+        parameters.extend(flatten_multirequest_calls(&self.calls));
+
+        let result = self.client.api_request("multirequest", "null", parameters).await?;
+        match serde_json::from_str::<Vec<serde_json::Value>>(&result) {
+            Ok(results) => Ok(results
+                .into_iter()
+                .map(|value| value.to_string())
+                .collect()),
+            Err(err) => Err(KalturaError::Deserialization(err)),
+        }
+    }
+}
+
+/// Numbers each queued call starting at `1` and flattens it into `{N:service}`/`{N:action}`/
+/// `{N:key}` parameters, matching Kaltura's `multirequest` convention.
+#[cfg(feature = "http")]
+fn flatten_multirequest_calls(
+    calls: &[(String, String, HashMap<String, String>)],
+) -> HashMap<String, String> {
+    let mut parameters = HashMap::new();
+    for (index, (service, action, call_params)) in calls.iter().enumerate() {
+        let call_number = index + 1;
+        parameters.insert(format!("{}:service", call_number), service.clone());
+        parameters.insert(format!("{}:action", call_number), action.clone());
+        for (key, value) in call_params {
+            parameters.insert(format!("{}:{}", call_number, key), value.clone());
+        }
+    }
+    parameters
+}
+
+#[cfg(feature = "http")]
 #[derive(Default)]
 pub struct KalturaClientBuilder {
     pub client: KalturaClient,
 }
 
+#[cfg(feature = "http")]
 impl KalturaClientBuilder {
     pub fn new() -> KalturaClientBuilder {
         KalturaClientBuilder {
@@ -233,14 +434,26 @@ impl KalturaClientBuilder {
 /// println!("{}", ks.ks);
 /// assert!(ks.ks.len() > 0);
 /// ```
+#[cfg(not(feature = "no_std"))]
 pub fn generate_session(session: &mut models::session::KalturaSession) {
+    generate_session_core(session, current_unix_time());
+}
+
+/// `no_std` builds have no wall clock, so the caller supplies the current unix timestamp
+/// (seconds since the epoch, as an `f32`).
+#[cfg(feature = "no_std")]
+pub fn generate_session(session: &mut models::session::KalturaSession, now: f32) {
+    generate_session_core(session, now);
+}
+
+fn generate_session_core(session: &mut models::session::KalturaSession, now: f32) {
     let session_data = format!(
         "{};{};{};{};{:.4};{};{};;",
         session.partner_id,
         session.partner_id,
-        session_duration(session.expiry as i32),
+        session_duration_at(session.expiry as i32, now),
         0,
-        session_duration(session.expiry),
+        session_duration_at(session.expiry, now),
         session.user_id,
         session.privileges,
     );
@@ -277,21 +490,51 @@ pub fn generate_session(session: &mut models::session::KalturaSession) {
 ///     ks: "".to_string(),
 /// };
 ///
-/// generate_session_v2(&mut session);
+/// generate_session_v2(&mut session).unwrap();
 ///
 /// assert!(session.ks.len() > 0);
 /// ```
-pub fn generate_session_v2(session: &mut models::session::KalturaSession) {
-    let session_duration = session_duration(session.expiry).to_string();
-    let mut session_data: HashMap<&str, &str> = HashMap::new();
-    session_data.insert("_e", &session_duration);
+///
+/// # Errors
+///
+/// Returns `Err(KalturaError::Crypto(_))` if the underlying AES-CBC encryption fails.
+#[cfg(not(feature = "no_std"))]
+pub fn generate_session_v2(
+    session: &mut models::session::KalturaSession,
+) -> Result<(), KalturaError> {
+    let random_bytes: [u8; crypto::AES_KEY_LEN] =
+        core::array::from_fn(|_| rand::Rng::gen_range(&mut rand::thread_rng(), 65..126));
+    generate_session_v2_core(session, current_unix_time(), random_bytes)
+}
+
+/// `no_std` builds have neither a wall clock nor an entropy source, so the caller supplies the
+/// current unix timestamp and the random bytes that would otherwise come from `rand::thread_rng`.
+///
+/// # Errors
+///
+/// Returns `Err(KalturaError::Crypto(_))` if the underlying AES-CBC encryption fails.
+#[cfg(feature = "no_std")]
+pub fn generate_session_v2(
+    session: &mut models::session::KalturaSession,
+    now: f32,
+    random_bytes: [u8; crypto::AES_KEY_LEN],
+) -> Result<(), KalturaError> {
+    generate_session_v2_core(session, now, random_bytes)
+}
+
+fn generate_session_v2_core(
+    session: &mut models::session::KalturaSession,
+    now: f32,
+    random_bytes: [u8; crypto::AES_KEY_LEN],
+) -> Result<(), KalturaError> {
+    let expiry = session_duration_at(session.expiry, now).to_string();
+    let mut session_data: Map<&str, &str> = Map::new();
+    session_data.insert("_e", &expiry);
     session_data.insert("_u", &session.user_id);
     session_data.insert("_t", "0");
     session_data.extend(generate_privileges(session));
 
-    let mut buffer: Vec<u8> = (0..crypto::AES_KEY_LEN)
-        .map(|_| rand::Rng::gen_range(&mut rand::thread_rng(), 65..126))
-        .collect();
+    let mut buffer: Vec<u8> = random_bytes.to_vec();
     buffer.extend(serde_qs::to_string(&session_data).unwrap().into_bytes());
 
     crypto::sha1(&buffer)
@@ -303,14 +546,15 @@ pub fn generate_session_v2(session: &mut models::session::KalturaSession) {
         &mut buffer,
         &crypto::sha1(&session.secret.as_bytes().to_vec()),
         &crypto::AES_IV,
-    );
+    )?;
 
     let mut ks: Vec<u8> = format!("v2|{}|", session.partner_id).into_bytes();
     ks.append(&mut buffer.to_vec());
     session.ks = URL_SAFE.encode(ks);
+    Ok(())
 }
 
-fn generate_privileges(session: &models::session::KalturaSession) -> HashMap<&str, &str> {
+fn generate_privileges(session: &models::session::KalturaSession) -> Map<&str, &str> {
     session
         .privileges
         .split(",")
@@ -325,15 +569,186 @@ fn generate_privileges(session: &models::session::KalturaSession) -> HashMap<&st
         .collect()
 }
 
-fn session_duration(duration: i32) -> f32 {
-    let expiry: f32 = time::SystemTime::now()
+/// Decodes and validates a Kaltura session string (`ks`), recognizing both the v1 and v2
+/// formats, and returns the `KalturaSession` it encodes.
+///
+/// # Arguments
+///
+/// * `ks` - The session string to decode, as produced by [`generate_session`] or
+///   [`generate_session_v2`].
+/// * `secret` - The partner secret the session was signed with.
+///
+/// # Errors
+///
+/// Returns `Err(KalturaError::InvalidSession(_))` if `ks` is not valid base64, is not long
+/// enough to contain its signature, or fails integrity verification (e.g. it was signed with a
+/// different secret).
+///
+/// # Example
+///
+/// ```
+/// use kaltura_client_rs::{generate_session_v2, parse_session, models::session::{KalturaSession, SessionType}};
+///
+/// let mut session = KalturaSession::new(
+///     "secret".to_string(),
+///     "joshua.navarro@kaltura.com".to_string(),
+///     4414853,
+///     3600,
+///     "disableentitlement".to_string(),
+///     SessionType::USER,
+/// );
+/// generate_session_v2(&mut session).unwrap();
+///
+/// let decoded = parse_session(&session.ks, "secret").unwrap();
+/// assert_eq!(decoded.user_id, "joshua.navarro@kaltura.com");
+/// ```
+pub fn parse_session(
+    ks: &str,
+    secret: &str,
+) -> Result<models::session::KalturaSession, KalturaError> {
+    let decoded = URL_SAFE
+        .decode(ks)
+        .map_err(|e| KalturaError::InvalidSession(format!("invalid base64: {}", e)))?;
+
+    let mut session = if decoded.starts_with(b"v2|") {
+        parse_session_v2(&decoded, secret)?
+    } else {
+        parse_session_v1(&decoded, secret)?
+    };
+    session.ks = ks.to_string();
+    Ok(session)
+}
+
+fn parse_session_v2(
+    decoded: &[u8],
+    secret: &str,
+) -> Result<models::session::KalturaSession, KalturaError> {
+    let rest = &decoded[3..];
+    let pipe_pos = rest
+        .iter()
+        .position(|&b| b == b'|')
+        .ok_or_else(|| KalturaError::InvalidSession("missing partner id in v2 ks".into()))?;
+    let partner_id: i32 = core::str::from_utf8(&rest[..pipe_pos])
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| KalturaError::InvalidSession("malformed partner id in v2 ks".into()))?;
+    let encrypted = &rest[pipe_pos + 1..];
+
+    let key = crypto::sha1(&secret.as_bytes().to_vec());
+    let plaintext = crypto::aes_decrypt(encrypted, &key, &crypto::AES_IV)?;
+
+    if plaintext.len() < crypto::AES_KEY_LEN + 20 {
+        return Err(KalturaError::InvalidSession(
+            "v2 ks payload too short".into(),
+        ));
+    }
+
+    let stored_digest = plaintext[..20].to_vec();
+    let payload = &plaintext[20..];
+
+    if crypto::sha1(&payload.to_vec()) != stored_digest {
+        return Err(KalturaError::InvalidSession(
+            "v2 ks integrity check failed".into(),
+        ));
+    }
+
+    let qs_bytes = &payload[crypto::AES_KEY_LEN..];
+    let qs = String::from_utf8_lossy(qs_bytes)
+        .trim_end_matches('\0')
+        .to_string();
+    let mut fields: Map<String, String> = serde_qs::from_str(&qs)
+        .map_err(|_| KalturaError::InvalidSession("malformed v2 ks fields".into()))?;
+
+    let expiry = fields
+        .remove("_e")
+        .and_then(|v| v.parse::<f32>().ok())
+        .unwrap_or_default() as i32;
+    let user_id = fields.remove("_u").unwrap_or_default();
+    fields.remove("_t");
+    let privileges = privileges_from_fields(&fields);
+
+    Ok(models::session::KalturaSession {
+        secret: secret.to_string(),
+        user_id,
+        partner_id,
+        expiry,
+        privileges,
+        session_type: models::session::SessionType::default(),
+        ks: String::new(),
+    })
+}
+
+fn parse_session_v1(
+    decoded: &[u8],
+    secret: &str,
+) -> Result<models::session::KalturaSession, KalturaError> {
+    if decoded.len() < 40 {
+        return Err(KalturaError::InvalidSession(
+            "v1 ks too short".into(),
+        ));
+    }
+    let (hash_hex, data) = decoded.split_at(40);
+    let hash_hex = core::str::from_utf8(hash_hex)
+        .map_err(|_| KalturaError::InvalidSession("malformed v1 ks signature".into()))?;
+    let data = core::str::from_utf8(data)
+        .map_err(|_| KalturaError::InvalidSession("malformed v1 ks data".into()))?;
+
+    let computed_hex = crypto::sha1(&format!("{}{}", secret, data).into_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect::<String>();
+    if computed_hex != hash_hex {
+        return Err(KalturaError::InvalidSession(
+            "v1 ks integrity check failed".into(),
+        ));
+    }
+
+    let fields: Vec<&str> = data.split(';').collect();
+    let partner_id = fields.first().and_then(|v| v.parse().ok()).unwrap_or_default();
+    let expiry = fields.get(4).and_then(|v| v.parse::<f32>().ok()).unwrap_or_default() as i32;
+    let user_id = fields.get(5).unwrap_or(&"").to_string();
+    let privileges = fields.get(6).unwrap_or(&"").to_string();
+
+    Ok(models::session::KalturaSession {
+        secret: secret.to_string(),
+        user_id,
+        partner_id,
+        expiry,
+        privileges,
+        session_type: models::session::SessionType::default(),
+        ks: String::new(),
+    })
+}
+
+/// Reconstructs a privileges string from the flattened query-string fields produced by
+/// [`generate_privileges`], e.g. `{"all": "*"}` becomes `"*"`.
+fn privileges_from_fields(fields: &Map<String, String>) -> String {
+    fields
+        .iter()
+        .map(|(key, value)| match (key.as_str(), value.as_str()) {
+            ("all", "*") => "*".to_string(),
+            (key, "") => key.to_string(),
+            (key, value) => format!("{}:{}", key, value),
+        })
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+/// Returns the current unix time as a float (seconds since the epoch). Unavailable under
+/// `no_std`, which has no wall clock — callers there supply `now` explicitly instead.
+#[cfg(not(feature = "no_std"))]
+fn current_unix_time() -> f32 {
+    time::SystemTime::now()
         .duration_since(time::UNIX_EPOCH)
         .unwrap()
         .as_secs_f32()
-        + duration as f32;
-    expiry
 }
 
+fn session_duration_at(duration: i32, now: f32) -> f32 {
+    now + duration as f32
+}
+
+#[cfg(feature = "http")]
 #[tokio::test]
 async fn system_service_test() {
     let kaltura_client: KalturaClient = KalturaClient::builder()
@@ -348,5 +763,103 @@ async fn system_service_test() {
         println!("Ks: {:?}", kaltura_client.session.ks);
     }
     println!("result: {:?}", result);
-    assert_eq!(result.len() > 0, true);
+    assert_eq!(result.is_ok(), true);
+}
+
+#[cfg(test)]
+mod session_tests {
+    use super::{generate_session_core, generate_session_v2_core, parse_session};
+    use crate::compat::ToString;
+    use crate::models::session::{KalturaSession, SessionType};
+
+    fn test_session() -> KalturaSession {
+        KalturaSession::new(
+            "top-secret".to_string(),
+            "joshua.navarro@kaltura.com".to_string(),
+            4414853,
+            3600,
+            "disableentitlement,list:1".to_string(),
+            SessionType::USER,
+        )
+    }
+
+    #[test]
+    fn v1_round_trips_through_generate_and_parse() {
+        let mut session = test_session();
+        generate_session_core(&mut session, 1_700_000_000.0);
+
+        let decoded = parse_session(&session.ks, "top-secret").unwrap();
+        assert_eq!(decoded.user_id, "joshua.navarro@kaltura.com");
+        assert_eq!(decoded.partner_id, 4414853);
+    }
+
+    #[test]
+    fn v1_rejects_the_wrong_secret() {
+        let mut session = test_session();
+        generate_session_core(&mut session, 1_700_000_000.0);
+
+        let result = parse_session(&session.ks, "wrong-secret");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn v2_round_trips_through_generate_and_parse() {
+        let mut session = test_session();
+        let random_bytes = [b'x'; crate::crypto::AES_KEY_LEN];
+        generate_session_v2_core(&mut session, 1_700_000_000.0, random_bytes).unwrap();
+
+        let decoded = parse_session(&session.ks, "top-secret").unwrap();
+        assert_eq!(decoded.user_id, "joshua.navarro@kaltura.com");
+        assert_eq!(decoded.partner_id, 4414853);
+    }
+
+    #[test]
+    fn v2_rejects_the_wrong_secret_without_panicking() {
+        let mut session = test_session();
+        let random_bytes = [b'x'; crate::crypto::AES_KEY_LEN];
+        generate_session_v2_core(&mut session, 1_700_000_000.0, random_bytes).unwrap();
+
+        let result = parse_session(&session.ks, "wrong-secret");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_session_rejects_malformed_input_without_panicking() {
+        assert!(parse_session("not valid base64!!", "top-secret").is_err());
+        assert!(parse_session("dG9vc2hvcnQ=", "top-secret").is_err());
+    }
+}
+
+#[cfg(feature = "http")]
+#[cfg(test)]
+mod multirequest_tests {
+    use super::flatten_multirequest_calls;
+    use std::collections::HashMap;
+
+    #[test]
+    fn numbers_calls_from_one_and_flattens_their_params() {
+        let calls = vec![
+            (
+                "system".to_string(),
+                "ping".to_string(),
+                HashMap::new(),
+            ),
+            (
+                "baseEntry".to_string(),
+                "get".to_string(),
+                HashMap::from([("entryId".to_string(), "{1:result:id}".to_string())]),
+            ),
+        ];
+
+        let params = flatten_multirequest_calls(&calls);
+
+        assert_eq!(params.get("1:service"), Some(&"system".to_string()));
+        assert_eq!(params.get("1:action"), Some(&"ping".to_string()));
+        assert_eq!(params.get("2:service"), Some(&"baseEntry".to_string()));
+        assert_eq!(params.get("2:action"), Some(&"get".to_string()));
+        assert_eq!(
+            params.get("2:entryId"),
+            Some(&"{1:result:id}".to_string())
+        );
+    }
 }
@@ -0,0 +1,5 @@
+#[cfg(feature = "http")]
+pub mod pager;
+pub mod session;
+#[cfg(feature = "http")]
+pub mod uiconf;
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// Kaltura's generic pager, accepted alongside a filter by `list` service calls.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KalturaFilterPager {
+    pub page_size: Option<i32>,
+    pub page_index: Option<i32>,
+}
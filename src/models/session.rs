@@ -1,3 +1,8 @@
+#[cfg(not(feature = "no_std"))]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::compat::{String, ToString};
+
 const DEFAULT_EXPIRY: i32 = 86400;
 
 
@@ -40,4 +45,23 @@ impl KalturaSession {
             ks: "".to_string(),
         }
     }
+
+    /// Returns `true` if this session's decoded expiry (as populated by
+    /// [`crate::parse_session`]) is at or before the current time.
+    #[cfg(not(feature = "no_std"))]
+    pub fn is_expired(&self) -> bool {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs() as i32;
+        self.is_expired_at(now)
+    }
+
+    /// Returns `true` if this session's decoded expiry (as populated by
+    /// [`crate::parse_session`]) is at or before `now` (a unix timestamp, in seconds).
+    ///
+    /// `no_std` builds have no wall clock, so the caller supplies the current time.
+    pub fn is_expired_at(&self, now: i32) -> bool {
+        self.expiry <= now
+    }
 }
\ No newline at end of file
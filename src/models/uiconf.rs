@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+
 pub enum KalturaUiConfObjType {
     Player = 1,
     ContributionWizard = 2,
@@ -28,55 +30,133 @@ pub enum KalturaUiConfCreationMode {
     SYSTEM = 4,
 }
 
+/// Kaltura dispatches `add`/`update` requests to the right class server-side based on this
+/// discriminator, so it must always be sent, not just when a caller happens to set it.
+const UI_CONF_OBJECT_TYPE: &str = "KalturaUiConf";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct KalturaUiConf {
-    id: Option<i32>,
-    conf_file: Option<String>,
-    conf_file_params: Option<String>,
-    conf_vars: Option<String>,
-    config: Option<String>,
-    created_at: Option<i32>,
-    creation_mode: Option<KalturaUiConfCreationMode>,
-    description: Option<String>,
-    height: Option<String>,
-    html5_url: Option<String>,
-    html_params: Option<String>,
-    name: Option<String>,
-    obj_type: Option<KalturaUiConfObjType>,
-    obj_type_as_string: Option<String>,
-    partner_id: Option<i32>,
-    partner_tags: Option<String>,
-    swf_url: Option<String>,
-    swf_url_version: Option<String>,
-    tags: Option<String>,
-    updated_at: Option<i32>,
-    use_cdn: Option<i32>,
-    version: Option<String>,
-    width: Option<String>,
+    #[serde(rename = "objectType")]
+    pub object_type: String,
+    pub id: Option<i32>,
+    pub conf_file: Option<String>,
+    pub conf_file_params: Option<String>,
+    pub conf_vars: Option<String>,
+    pub config: Option<String>,
+    pub created_at: Option<i32>,
+    /// One of the [`KalturaUiConfCreationMode`] variants.
+    pub creation_mode: Option<i32>,
+    pub description: Option<String>,
+    pub height: Option<String>,
+    pub html5_url: Option<String>,
+    pub html_params: Option<String>,
+    pub name: Option<String>,
+    /// One of the [`KalturaUiConfObjType`] variants.
+    pub obj_type: Option<i32>,
+    pub obj_type_as_string: Option<String>,
+    pub partner_id: Option<i32>,
+    pub partner_tags: Option<String>,
+    pub swf_url: Option<String>,
+    pub swf_url_version: Option<String>,
+    pub tags: Option<String>,
+    pub updated_at: Option<i32>,
+    pub use_cdn: Option<i32>,
+    pub version: Option<String>,
+    pub width: Option<String>,
+}
+
+impl Default for KalturaUiConf {
+    fn default() -> Self {
+        KalturaUiConf {
+            object_type: UI_CONF_OBJECT_TYPE.to_string(),
+            id: None,
+            conf_file: None,
+            conf_file_params: None,
+            conf_vars: None,
+            config: None,
+            created_at: None,
+            creation_mode: None,
+            description: None,
+            height: None,
+            html5_url: None,
+            html_params: None,
+            name: None,
+            obj_type: None,
+            obj_type_as_string: None,
+            partner_id: None,
+            partner_tags: None,
+            swf_url: None,
+            swf_url_version: None,
+            tags: None,
+            updated_at: None,
+            use_cdn: None,
+            version: None,
+            width: None,
+        }
+    }
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct KalturaUiConfListResponse {
-    obj_type: Option<String>,
-    objects: Vec<KalturaUiConf>,
-    total_count: i32,
+    #[serde(rename = "objectType", skip_serializing_if = "Option::is_none")]
+    pub object_type: Option<String>,
+    #[serde(default)]
+    pub objects: Vec<KalturaUiConf>,
+    pub total_count: i32,
 }
 
-// pub struct UiConfBaseFilter {
-//     IdEqual: Option<i32>,
-//     IdIn: Option<String>,
-//     IdNotIn: Option<String>,
-//     NameLike: Option<String>,
-//     SystemNameLike: Option<String>,
-//     PartnerIdEqual: Option<i32>,
-//     PartnerIdIn: Option<String>,
-//     PartnerIdNotIn: Option<String>,
-//     ObjTypeEqual: Option<i32>,
-//     ObjTypeIn: Option<String>,
-//     ObjTypeNotIn: Option<String>,
-//     TagsMultiLikeOr: Option<String>,
-//     TagsMultiLikeAnd: Option<String>,
-//     TagsNameMultiLikeOr: Option<String>,
-//     TagsNameMultiLikeAnd: Option<String>,
-//     OrderBy: Option<String>,
-//     AdvancedSearch: Option<String>,
-//     Pager: Option<String>,
-// }
+/// Kaltura's `list` dispatches on the filter's `objectType` the same way `add`/`update` do on
+/// the entity's, to know which filter subclass was flattened into the `filter:*` params.
+const UI_CONF_FILTER_OBJECT_TYPE: &str = "KalturaUiConfFilter";
+
+/// Filter input for [`crate::service::uiconf::UiConfService::list`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UiConfBaseFilter {
+    #[serde(rename = "objectType")]
+    pub object_type: String,
+    pub id_equal: Option<i32>,
+    pub id_in: Option<String>,
+    pub id_not_in: Option<String>,
+    pub name_like: Option<String>,
+    pub system_name_like: Option<String>,
+    pub partner_id_equal: Option<i32>,
+    pub partner_id_in: Option<String>,
+    pub partner_id_not_in: Option<String>,
+    pub obj_type_equal: Option<i32>,
+    pub obj_type_in: Option<String>,
+    pub obj_type_not_in: Option<String>,
+    pub tags_multi_like_or: Option<String>,
+    pub tags_multi_like_and: Option<String>,
+    pub tags_name_multi_like_or: Option<String>,
+    pub tags_name_multi_like_and: Option<String>,
+    pub order_by: Option<String>,
+    pub advanced_search: Option<String>,
+}
+
+impl Default for UiConfBaseFilter {
+    fn default() -> Self {
+        UiConfBaseFilter {
+            object_type: UI_CONF_FILTER_OBJECT_TYPE.to_string(),
+            id_equal: None,
+            id_in: None,
+            id_not_in: None,
+            name_like: None,
+            system_name_like: None,
+            partner_id_equal: None,
+            partner_id_in: None,
+            partner_id_not_in: None,
+            obj_type_equal: None,
+            obj_type_in: None,
+            obj_type_not_in: None,
+            tags_multi_like_or: None,
+            tags_multi_like_and: None,
+            tags_name_multi_like_or: None,
+            tags_name_multi_like_and: None,
+            order_by: None,
+            advanced_search: None,
+        }
+    }
+}
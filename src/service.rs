@@ -1,33 +1,44 @@
+use crate::{error::KalturaError, KalturaClient};
+
 pub mod system;
 pub mod uiconf;
-use crate::KalturaClient;
 
-trait List<T> {
-    fn list(&self) -> T;
-}
+/// Lists objects matching `filter`, one page at a time as described by `pager`.
+///
+/// These CRUD traits are only ever called on concrete service types, never as `dyn Trait`,
+/// so the `Send` bound `async_fn_in_trait` warns about not being guaranteed doesn't matter here.
+#[allow(async_fn_in_trait)]
+pub trait List<TFilter, TPager> {
+    type Output;
 
-trait Get<T> {
-    fn get(&self, id: i32) -> T;
+    async fn list(&self, filter: TFilter, pager: TPager) -> Result<Self::Output, KalturaError>;
 }
 
-trait Delete<T> {
-    fn delete(&self, id: i32) -> T;
+/// Fetches a single object by id.
+#[allow(async_fn_in_trait)]
+pub trait Get<T> {
+    async fn get(&self, id: i32) -> Result<T, KalturaError>;
 }
 
-trait Add<T> {
-    fn add(&self, obj: T) -> T;
+/// Deletes a single object by id.
+#[allow(async_fn_in_trait)]
+pub trait Delete {
+    async fn delete(&self, id: i32) -> Result<(), KalturaError>;
 }
 
-trait Update<T> {
-    fn update(&self, id: i32, obj: T) -> T;
+/// Creates a new object.
+#[allow(async_fn_in_trait)]
+pub trait Add<T> {
+    async fn add(&self, obj: T) -> Result<T, KalturaError>;
 }
 
-trait Service {
-    fn new(client: &KalturaClient) -> T;
+/// Updates an existing object, identified by id.
+#[allow(async_fn_in_trait)]
+pub trait Update<T> {
+    async fn update(&self, id: i32, obj: T) -> Result<T, KalturaError>;
 }
 
-impl<T> Service<T> for T {
-    fn new(client: &KalturaClient) -> T {
-        T { client }
-    }
-}
\ No newline at end of file
+/// Constructs a service bound to a `KalturaClient`, shared by every `*Service` type.
+pub trait Service<'client> {
+    fn new(client: &'client KalturaClient) -> Self;
+}
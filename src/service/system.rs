@@ -1,4 +1,4 @@
-use crate::KalturaClient;
+use crate::{error::KalturaError, service::Service, KalturaClient};
 
 const SERVICE_PATH: &str = "system";
 
@@ -6,23 +6,22 @@ pub struct SystemService<'client> {
     client: &'client KalturaClient,
 }
 
-impl<'client> SystemService<'client> {
-    pub fn new(client: &'client KalturaClient) -> SystemService<'client> {
+impl<'client> Service<'client> for SystemService<'client> {
+    fn new(client: &'client KalturaClient) -> SystemService<'client> {
         SystemService { client }
     }
+}
 
-    pub async fn ping(&self) -> String {
-        let result = self.client.api_get(SERVICE_PATH, "ping").await.unwrap();
-        result
+impl<'client> SystemService<'client> {
+    pub async fn ping(&self) -> Result<String, KalturaError> {
+        self.client.api_get(SERVICE_PATH, "ping").await
     }
 
-    pub async fn get_time(&self) -> String {
-        let result = self.client.api_get(SERVICE_PATH, "getTime").await.unwrap();
-        result
+    pub async fn get_time(&self) -> Result<String, KalturaError> {
+        self.client.api_get(SERVICE_PATH, "getTime").await
     }
 
-    pub async fn get_version(&self) -> String {
-        let result = self.client.api_get(SERVICE_PATH, "getVersion").await.unwrap();
-        result
+    pub async fn get_version(&self) -> Result<String, KalturaError> {
+        self.client.api_get(SERVICE_PATH, "getVersion").await
     }
 }
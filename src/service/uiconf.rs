@@ -1,4 +1,14 @@
-use crate::KalturaClient;
+use std::collections::HashMap;
+
+use crate::{
+    error::KalturaError,
+    models::{
+        pager::KalturaFilterPager,
+        uiconf::{KalturaUiConf, KalturaUiConfListResponse, UiConfBaseFilter},
+    },
+    service::{Add, Delete, Get, List, Service, Update},
+    KalturaClient,
+};
 
 const SERVICE_PATH: &str = "uiconf";
 
@@ -6,8 +16,119 @@ pub struct UiConfService<'client> {
     client: &'client KalturaClient,
 }
 
-impl<'client> UiConfService<'client> {
-    pub fn new(client: &'client KalturaClient) -> UiConfService<'client> {
+impl<'client> Service<'client> for UiConfService<'client> {
+    fn new(client: &'client KalturaClient) -> UiConfService<'client> {
         UiConfService { client }
     }
 }
+
+/// Flattens a serializable object into `{prefix}:{field}` request parameters, the convention
+/// Kaltura uses for passing objects (filters, pagers, entities) as part of a request.
+fn to_params<T: serde::Serialize>(
+    prefix: &str,
+    value: &T,
+) -> Result<HashMap<String, String>, KalturaError> {
+    let mut params = HashMap::new();
+    if let serde_json::Value::Object(fields) = serde_json::to_value(value)? {
+        for (key, value) in fields {
+            if value.is_null() {
+                continue;
+            }
+            let value = match value {
+                serde_json::Value::String(s) => s,
+                other => other.to_string(),
+            };
+            params.insert(format!("{}:{}", prefix, key), value);
+        }
+    }
+    Ok(params)
+}
+
+impl<'client> List<UiConfBaseFilter, KalturaFilterPager> for UiConfService<'client> {
+    type Output = KalturaUiConfListResponse;
+
+    async fn list(
+        &self,
+        filter: UiConfBaseFilter,
+        pager: KalturaFilterPager,
+    ) -> Result<Self::Output, KalturaError> {
+        let mut params = to_params("filter", &filter)?;
+        params.extend(to_params("pager", &pager)?);
+        let result = self.client.api_request(SERVICE_PATH, "list", params).await?;
+        Ok(serde_json::from_str(&result)?)
+    }
+}
+
+impl<'client> Get<KalturaUiConf> for UiConfService<'client> {
+    async fn get(&self, id: i32) -> Result<KalturaUiConf, KalturaError> {
+        let params = HashMap::from([("uiConfId".to_string(), id.to_string())]);
+        let result = self.client.api_request(SERVICE_PATH, "get", params).await?;
+        Ok(serde_json::from_str(&result)?)
+    }
+}
+
+impl<'client> Add<KalturaUiConf> for UiConfService<'client> {
+    async fn add(&self, obj: KalturaUiConf) -> Result<KalturaUiConf, KalturaError> {
+        let params = to_params("uiConf", &obj)?;
+        let result = self.client.api_request(SERVICE_PATH, "add", params).await?;
+        Ok(serde_json::from_str(&result)?)
+    }
+}
+
+impl<'client> Update<KalturaUiConf> for UiConfService<'client> {
+    async fn update(&self, id: i32, obj: KalturaUiConf) -> Result<KalturaUiConf, KalturaError> {
+        let mut params = HashMap::from([("uiConfId".to_string(), id.to_string())]);
+        params.extend(to_params("uiConf", &obj)?);
+        let result = self.client.api_request(SERVICE_PATH, "update", params).await?;
+        Ok(serde_json::from_str(&result)?)
+    }
+}
+
+impl<'client> Delete for UiConfService<'client> {
+    async fn delete(&self, id: i32) -> Result<(), KalturaError> {
+        let params = HashMap::from([("uiConfId".to_string(), id.to_string())]);
+        self.client.api_request(SERVICE_PATH, "delete", params).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_params;
+    use crate::models::uiconf::{KalturaUiConf, UiConfBaseFilter};
+
+    #[test]
+    fn flattens_fields_under_the_given_prefix() {
+        let uiconf = KalturaUiConf {
+            name: Some("My Player".to_string()),
+            ..KalturaUiConf::default()
+        };
+
+        let params = to_params("uiConf", &uiconf).unwrap();
+
+        assert_eq!(params.get("uiConf:name"), Some(&"My Player".to_string()));
+        assert_eq!(
+            params.get("uiConf:objectType"),
+            Some(&"KalturaUiConf".to_string())
+        );
+    }
+
+    #[test]
+    fn skips_null_fields() {
+        let uiconf = KalturaUiConf::default();
+        let params = to_params("uiConf", &uiconf).unwrap();
+
+        assert!(!params.contains_key("uiConf:name"));
+    }
+
+    #[test]
+    fn filter_always_carries_its_object_type() {
+        let filter = UiConfBaseFilter::default();
+        let params = to_params("filter", &filter).unwrap();
+
+        assert_eq!(
+            params.get("filter:objectType"),
+            Some(&"KalturaUiConfFilter".to_string())
+        );
+    }
+}